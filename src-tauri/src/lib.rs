@@ -4,8 +4,8 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use timer::{
-    get_timer_state, pause_timer, reset_timer, start_timer, TimerState, TimerStateHandle,
-    DEFAULT_TIMER_SECONDS,
+    finish_timer, get_timer_config, get_timer_state, pause_timer, reset_timer, set_phase_timers,
+    start_timer, TimerState, TimerStateHandle, DEFAULT_TIMER_SECONDS,
 };
 
 #[tauri::command]
@@ -27,7 +27,10 @@ pub fn run() {
             start_timer,
             pause_timer,
             reset_timer,
-            get_timer_state
+            finish_timer,
+            get_timer_state,
+            get_timer_config,
+            set_phase_timers
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
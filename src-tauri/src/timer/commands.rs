@@ -1,13 +1,14 @@
 // Tauri commands for timer control
 // Called from TypeScript via invoke()
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use tauri::{AppHandle, State};
 use tokio::sync::Mutex;
 
-use super::service::{emit_timer_state, run_timer_loop};
-use super::state::{TimerSnapshot, TimerState, TimerStatus};
+use super::service::{emit_timer_finished, emit_timer_state, run_timer_loop};
+use super::state::{TimerConfig, TimerSnapshot, TimerState, ACTION_TYPES, PLAYERS};
 
 /// Type alias for the managed timer state
 pub type TimerStateHandle = Arc<Mutex<TimerState>>;
@@ -19,16 +20,8 @@ pub async fn start_timer(
 ) -> Result<TimerSnapshot, String> {
     let mut guard = state.lock().await;
 
-    // Validate current state
-    if guard.status != TimerStatus::Ready && guard.status != TimerStatus::Paused {
-        return Err(format!(
-            "Cannot start timer in {:?} state. Must be 'ready' or 'paused'.",
-            guard.status
-        ));
-    }
-
-    // Update status to running
-    guard.status = TimerStatus::Running;
+    // Validates state and stops any loop left over from a previous run
+    let (run_id, stop_rx) = guard.start()?;
     let snapshot = guard.snapshot();
 
     // Emit initial state to all windows
@@ -41,7 +34,7 @@ pub async fn start_timer(
     drop(guard);
 
     // Spawn the timer loop in background
-    tauri::async_runtime::spawn(run_timer_loop(app, state_clone));
+    tauri::async_runtime::spawn(run_timer_loop(app, state_clone, run_id, stop_rx));
 
     Ok(snapshot)
 }
@@ -53,17 +46,8 @@ pub async fn pause_timer(
 ) -> Result<TimerSnapshot, String> {
     let mut guard = state.lock().await;
 
-    // Validate current state
-    if guard.status != TimerStatus::Running {
-        return Err(format!(
-            "Cannot pause timer in {:?} state. Must be 'running'.",
-            guard.status
-        ));
-    }
-
-    // Stop the running loop
-    guard.send_stop_signal();
-    guard.status = TimerStatus::Paused;
+    // Validates state and stops the running loop
+    guard.pause()?;
 
     let snapshot = guard.snapshot();
     emit_timer_state(&app, &snapshot);
@@ -71,14 +55,27 @@ pub async fn pause_timer(
     Ok(snapshot)
 }
 
+/// Reset the timer
+/// Explicit seconds take priority, then the phase timer for action_type
 #[tauri::command]
 pub async fn reset_timer(
     app: AppHandle,
     state: State<'_, TimerStateHandle>,
     seconds: Option<u32>,
+    action_type: Option<String>,
 ) -> Result<TimerSnapshot, String> {
+    if let Some(action_type) = action_type.as_deref() {
+        validate_action_type(action_type)?;
+    }
+
     let mut guard = state.lock().await;
 
+    let seconds = seconds.or_else(|| {
+        action_type
+            .as_deref()
+            .map(|action_type| guard.seconds_for_action(action_type))
+    });
+
     // Reset handles stopping any running timer
     guard.reset(seconds);
 
@@ -88,10 +85,84 @@ pub async fn reset_timer(
     Ok(snapshot)
 }
 
+/// End the current turn early, as if time ran out
+/// Emits timer-finished with the player whose turn was cut short, if given
+#[tauri::command]
+pub async fn finish_timer(
+    app: AppHandle,
+    state: State<'_, TimerStateHandle>,
+    player: Option<String>,
+) -> Result<TimerSnapshot, String> {
+    if let Some(player) = player.as_deref() {
+        validate_player(player)?;
+    }
+
+    let mut guard = state.lock().await;
+    guard.finish()?;
+
+    let snapshot = guard.snapshot();
+    emit_timer_state(&app, &snapshot);
+    emit_timer_finished(&app, player);
+
+    Ok(snapshot)
+}
+
 /// Get current timer state
 /// Useful for initial sync when overlay opens
 #[tauri::command]
 pub async fn get_timer_state(state: State<'_, TimerStateHandle>) -> Result<TimerSnapshot, String> {
     let guard = state.lock().await;
     Ok(guard.snapshot())
-}
\ No newline at end of file
+}
+
+/// Get the effective timer configuration
+/// Includes the default duration and any per-action overrides
+#[tauri::command]
+pub async fn get_timer_config(state: State<'_, TimerStateHandle>) -> Result<TimerConfig, String> {
+    let guard = state.lock().await;
+    Ok(guard.config())
+}
+
+/// Configure per-action turn durations (e.g. MAP_BAN: 20, AGENT_PICK: 30)
+/// Unset action types fall back to DEFAULT_TIMER_SECONDS
+#[tauri::command]
+pub async fn set_phase_timers(
+    state: State<'_, TimerStateHandle>,
+    timers: HashMap<String, u32>,
+) -> Result<HashMap<String, u32>, String> {
+    for (action_type, seconds) in &timers {
+        validate_action_type(action_type)?;
+        if *seconds == 0 {
+            return Err(format!("Timer for {} must be greater than 0.", action_type));
+        }
+    }
+
+    let mut guard = state.lock().await;
+    guard.set_phase_timers(timers);
+
+    Ok(guard.phase_timers().clone())
+}
+
+/// Reject action types that have no phase timer slot
+fn validate_action_type(action_type: &str) -> Result<(), String> {
+    if !ACTION_TYPES.contains(&action_type) {
+        return Err(format!(
+            "Unknown action type '{}'. Must be one of: {}.",
+            action_type,
+            ACTION_TYPES.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Reject players other than P1 and P2
+fn validate_player(player: &str) -> Result<(), String> {
+    if !PLAYERS.contains(&player) {
+        return Err(format!(
+            "Unknown player '{}'. Must be one of: {}.",
+            player,
+            PLAYERS.join(", ")
+        ));
+    }
+    Ok(())
+}
@@ -6,5 +6,8 @@ pub mod service;
 pub mod state;
 
 // Re-export commonly used items
-pub use commands::{get_timer_state, pause_timer, reset_timer, start_timer, TimerStateHandle};
+pub use commands::{
+    finish_timer, get_timer_config, get_timer_state, pause_timer, reset_timer, set_phase_timers,
+    start_timer, TimerStateHandle,
+};
 pub use state::{TimerState, DEV_TIMER_SECONDS, DEFAULT_TIMER_SECONDS};
\ No newline at end of file
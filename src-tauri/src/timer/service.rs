@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use tauri::{AppHandle, Emitter};
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tokio::time::interval;
 
 use super::state::{TimerSnapshot, TimerState, TimerStatus};
@@ -40,6 +40,13 @@ impl From<&TimerSnapshot> for TimerTickPayload {
     }
 }
 
+/// Payload sent with timer-finished events
+/// player is set when an admin ends a turn early, None on natural expiry
+#[derive(Clone, serde::Serialize)]
+pub struct TimerFinishedPayload {
+    pub player: Option<String>,
+}
+
 /// Emit current timer state to all windows
 pub fn emit_timer_state(app: &AppHandle, snapshot: &TimerSnapshot) {
     let payload = TimerTickPayload::from(snapshot);
@@ -48,14 +55,23 @@ pub fn emit_timer_state(app: &AppHandle, snapshot: &TimerSnapshot) {
     }
 }
 
+/// Emit timer-finished to all windows
+pub fn emit_timer_finished(app: &AppHandle, player: Option<String>) {
+    let payload = TimerFinishedPayload { player };
+    if let Err(e) = app.emit("timer-finished", &payload) {
+        eprintln!("Failed to emit timer-finished event: {}", e);
+    }
+}
+
 /// Async timer loop that runs in background
 /// Decrements timer every second and emits events
-pub async fn run_timer_loop(app: AppHandle, state: Arc<Mutex<TimerState>>) {
-    let mut stop_rx = {
-        let guard = state.lock().await;
-        guard.get_stop_receiver()
-    };
-
+/// Exits as soon as another run replaces it, so only one loop ever counts down
+pub async fn run_timer_loop(
+    app: AppHandle,
+    state: Arc<Mutex<TimerState>>,
+    run_id: u64,
+    mut stop_rx: watch::Receiver<bool>,
+) {
     // Create 1-second interval ticker
     let mut ticker = interval(Duration::from_secs(1));
 
@@ -66,22 +82,14 @@ pub async fn run_timer_loop(app: AppHandle, state: Arc<Mutex<TimerState>>) {
         tokio::select! {
             _ = ticker.tick() => {
                 let mut guard = state.lock().await;
-                if guard.status != TimerStatus::Running {
+                if !guard.tick(run_id) {
                     break;
                 }
-                if guard.seconds > 0 {
-                    guard.seconds -= 1;
-                    if guard.seconds == 0 {
-                        guard.status = TimerStatus::Finished;
-                    }
-                    let snapshot = guard.snapshot();
-                    emit_timer_state(&app, &snapshot);
-                    if guard.seconds == 0 {
-                        if let Err(e) = app.emit("timer-finished", ()) {
-                            eprintln!("Failed to emit timer-finished event: {}", e);
-                        }
-                        break;
-                    }
+                let snapshot = guard.snapshot();
+                emit_timer_state(&app, &snapshot);
+                if guard.status == TimerStatus::Finished {
+                    emit_timer_finished(&app, None);
+                    break;
                 }
             }
 
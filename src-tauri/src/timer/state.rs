@@ -1,5 +1,7 @@
 // Timer state management
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use tokio::sync::watch;
 
@@ -27,26 +29,40 @@ pub struct TimerSnapshot {
     pub initial_seconds: u32,
 }
 
+/// Serializable view of the effective timer configuration
+#[derive(Clone, Debug, Serialize)]
+pub struct TimerConfig {
+    pub default_seconds: u32,
+    pub initial_seconds: u32,
+    pub phase_timers: HashMap<String, u32>,
+}
+
 /// Internal timer state with control channels
 pub struct TimerState {
     pub status: TimerStatus,
     pub seconds: u32,
     pub initial_seconds: u32,
 
-    /// Channel to signal timer loop to stop
+    /// Per-action turn durations (e.g. "MAP_BAN" -> 20)
+    phase_timers: HashMap<String, u32>,
+
+    /// Identifier of the active timer loop, bumped on every start
+    run_id: u64,
+
+    /// Channel to signal the active timer loop to stop
     stop_signal: watch::Sender<bool>,
-    stop_receiver: watch::Receiver<bool>,
 }
 
 impl TimerState {
     pub fn new(initial_seconds: u32) -> Self {
-        let (tx, rx) = watch::channel(false);
+        let (tx, _) = watch::channel(false);
         Self {
             status: TimerStatus::Ready,
             seconds: initial_seconds,
             initial_seconds,
+            phase_timers: HashMap::new(),
+            run_id: 0,
             stop_signal: tx,
-            stop_receiver: rx,
         }
     }
 
@@ -59,9 +75,86 @@ impl TimerState {
         }
     }
 
-    /// Get a clone of the stop receiver for the timer loop
-    pub fn get_stop_receiver(&self) -> watch::Receiver<bool> {
-        self.stop_receiver.clone()
+    /// Replace the per-action turn durations
+    pub fn set_phase_timers(&mut self, timers: HashMap<String, u32>) {
+        self.phase_timers = timers;
+    }
+
+    /// Get the configured per-action turn durations
+    pub fn phase_timers(&self) -> &HashMap<String, u32> {
+        &self.phase_timers
+    }
+
+    /// Get the effective timer configuration
+    pub fn config(&self) -> TimerConfig {
+        TimerConfig {
+            default_seconds: DEFAULT_TIMER_SECONDS,
+            initial_seconds: self.initial_seconds,
+            phase_timers: self.phase_timers.clone(),
+        }
+    }
+
+    /// Resolve the turn duration for an action type
+    /// Falls back to DEFAULT_TIMER_SECONDS when no override is set
+    pub fn seconds_for_action(&self, action_type: &str) -> u32 {
+        self.phase_timers
+            .get(action_type)
+            .copied()
+            .unwrap_or(DEFAULT_TIMER_SECONDS)
+    }
+
+    /// Start or resume the timer
+    /// Returns the new run id and stop receiver for the timer loop
+    pub fn start(&mut self) -> Result<(u64, watch::Receiver<bool>), String> {
+        if self.status != TimerStatus::Ready && self.status != TimerStatus::Paused {
+            return Err(format!(
+                "Cannot start timer in {:?} state. Must be 'ready' or 'paused'.",
+                self.status
+            ));
+        }
+
+        // Stop any loop left over from a previous run before handing out a new one
+        self.status = TimerStatus::Running;
+        Ok(self.begin_run())
+    }
+
+    /// Pause the running timer and stop its loop
+    pub fn pause(&mut self) -> Result<(), String> {
+        if self.status != TimerStatus::Running {
+            return Err(format!(
+                "Cannot pause timer in {:?} state. Must be 'running'.",
+                self.status
+            ));
+        }
+
+        self.send_stop_signal();
+        self.status = TimerStatus::Paused;
+        Ok(())
+    }
+
+    /// Begin a new timer loop run
+    /// Stops any previous loop and returns the new run id with a fresh stop receiver
+    fn begin_run(&mut self) -> (u64, watch::Receiver<bool>) {
+        self.send_stop_signal();
+        self.run_id += 1;
+
+        let (tx, rx) = watch::channel(false);
+        self.stop_signal = tx;
+        (self.run_id, rx)
+    }
+
+    /// Count down one second for the given run
+    /// Returns false if the run is stale or the timer is not running, so the loop should exit
+    pub fn tick(&mut self, run_id: u64) -> bool {
+        if run_id != self.run_id || self.status != TimerStatus::Running {
+            return false;
+        }
+
+        self.seconds = self.seconds.saturating_sub(1);
+        if self.seconds == 0 {
+            self.status = TimerStatus::Finished;
+        }
+        true
     }
 
     /// Send stop signal to terminate running timer loop
@@ -69,6 +162,21 @@ impl TimerState {
         let _ = self.stop_signal.send(true);
     }
 
+    /// Finish the timer immediately, as if time ran out
+    pub fn finish(&mut self) -> Result<(), String> {
+        if self.status != TimerStatus::Running && self.status != TimerStatus::Paused {
+            return Err(format!(
+                "Cannot finish timer in {:?} state. Must be 'running' or 'paused'.",
+                self.status
+            ));
+        }
+
+        self.send_stop_signal();
+        self.status = TimerStatus::Finished;
+        self.seconds = 0;
+        Ok(())
+    }
+
     /// Reset timer to initial or specified seconds
     pub fn reset(&mut self, seconds: Option<u32>) {
         self.send_stop_signal();
@@ -77,11 +185,6 @@ impl TimerState {
         self.status = TimerStatus::Ready;
         self.seconds = new_seconds;
         self.initial_seconds = new_seconds;
-
-        // Create new stop channel for next timer run
-        let (tx, rx) = watch::channel(false);
-        self.stop_signal = tx;
-        self.stop_receiver = rx;
     }
 }
 
@@ -89,6 +192,12 @@ impl TimerState {
 pub const DEV_TIMER_SECONDS: u32 = 3;
 pub const DEFAULT_TIMER_SECONDS: u32 = 30;
 
+/// Action types that accept a per-phase timer override (matching TypeScript ActionType)
+pub const ACTION_TYPES: [&str; 5] = ["MAP_BAN", "MAP_PICK", "DECIDER", "AGENT_BAN", "AGENT_PICK"];
+
+/// Players that can be named when finishing a turn early (matching TypeScript Player)
+pub const PLAYERS: [&str; 2] = ["P1", "P2"];
+
 // Basic tests
 #[cfg(test)]
 mod tests {
@@ -137,4 +246,131 @@ mod tests {
         assert_eq!(snapshot.seconds, 25);
         assert_eq!(snapshot.initial_seconds, 25);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_seconds_for_action_defaults() {
+        let state = TimerState::new(30);
+
+        assert_eq!(state.seconds_for_action("MAP_BAN"), DEFAULT_TIMER_SECONDS);
+        assert_eq!(
+            state.seconds_for_action("AGENT_PICK"),
+            DEFAULT_TIMER_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_seconds_for_action_with_overrides() {
+        let mut state = TimerState::new(30);
+        state.set_phase_timers(HashMap::from([
+            ("MAP_BAN".to_string(), 20),
+            ("DECIDER".to_string(), 15),
+        ]));
+
+        assert_eq!(state.seconds_for_action("MAP_BAN"), 20);
+        assert_eq!(state.seconds_for_action("DECIDER"), 15);
+        assert_eq!(
+            state.seconds_for_action("AGENT_PICK"),
+            DEFAULT_TIMER_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_tick_counts_down_to_finished() {
+        let mut state = TimerState::new(2);
+        let (run_id, _stop_rx) = state.start().unwrap();
+
+        assert!(state.tick(run_id));
+        assert_eq!(state.seconds, 1);
+        assert_eq!(state.status, TimerStatus::Running);
+
+        assert!(state.tick(run_id));
+        assert_eq!(state.seconds, 0);
+        assert_eq!(state.status, TimerStatus::Finished);
+
+        assert!(!state.tick(run_id));
+    }
+
+    #[test]
+    fn test_start_rejected_while_running() {
+        let mut state = TimerState::new(30);
+        state.start().unwrap();
+
+        assert!(state.start().is_err());
+        assert_eq!(state.status, TimerStatus::Running);
+    }
+
+    #[test]
+    fn test_pause_rejected_unless_running() {
+        let mut state = TimerState::new(30);
+
+        assert!(state.pause().is_err());
+        assert_eq!(state.status, TimerStatus::Ready);
+    }
+
+    #[test]
+    fn test_resume_stops_previous_loop() {
+        let mut state = TimerState::new(30);
+        let (_, old_stop_rx) = state.start().unwrap();
+        state.pause().unwrap();
+        let (_, new_stop_rx) = state.start().unwrap();
+
+        assert!(*old_stop_rx.borrow());
+        assert!(!*new_stop_rx.borrow());
+    }
+
+    #[test]
+    fn test_rapid_start_pause_decrements_at_single_speed() {
+        let mut state = TimerState::new(30);
+        let mut run_ids = Vec::new();
+
+        // Hammer start/pause, leaving a loop behind from each start
+        for _ in 0..10 {
+            let (run_id, _) = state.start().unwrap();
+            run_ids.push(run_id);
+            state.pause().unwrap();
+        }
+
+        let (current_run, _) = state.start().unwrap();
+        run_ids.push(current_run);
+
+        // Every loop that was ever spawned wakes up for one tick
+        let ticked = run_ids.iter().filter(|run_id| state.tick(**run_id)).count();
+
+        assert_eq!(ticked, 1);
+        assert_eq!(state.seconds, 29);
+    }
+
+    #[test]
+    fn test_config_reflects_phase_timers() {
+        let mut state = TimerState::new(25);
+        state.set_phase_timers(HashMap::from([("AGENT_PICK".to_string(), 40)]));
+
+        let config = state.config();
+
+        assert_eq!(config.default_seconds, DEFAULT_TIMER_SECONDS);
+        assert_eq!(config.initial_seconds, 25);
+        assert_eq!(config.phase_timers.get("AGENT_PICK"), Some(&40));
+    }
+
+    #[test]
+    fn test_finish_stops_running_loop() {
+        let mut state = TimerState::new(30);
+        let (run_id, stop_rx) = state.start().unwrap();
+
+        state.finish().unwrap();
+
+        assert_eq!(state.status, TimerStatus::Finished);
+        assert_eq!(state.seconds, 0);
+        assert_eq!(state.initial_seconds, 30);
+        assert!(*stop_rx.borrow());
+        assert!(!state.tick(run_id));
+    }
+
+    #[test]
+    fn test_finish_rejected_unless_running_or_paused() {
+        let mut state = TimerState::new(30);
+        assert!(state.finish().is_err());
+        assert_eq!(state.status, TimerStatus::Ready);
+        assert_eq!(state.seconds, 30);
+    }
+}
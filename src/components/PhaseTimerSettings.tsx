@@ -0,0 +1,90 @@
+import { useEffect, useState } from 'react';
+import { useTimerStore } from '../store/timerStore';
+import { DEFAULT_TIMER_SECONDS } from '../core/tournament/constants';
+import type { ActionType } from '../types/admin.types';
+import type { PhaseTimers, RustTimerConfig } from '../core/timer/types';
+
+const PHASE_TIMER_LABELS: ReadonlyArray<[ActionType, string]> = [
+  ['MAP_BAN', 'Map Ban'],
+  ['MAP_PICK', 'Map Pick'],
+  ['DECIDER', 'Decider'],
+  ['AGENT_BAN', 'Agent Ban'],
+  ['AGENT_PICK', 'Agent Pick'],
+];
+
+const defaultInputs = (): Record<ActionType, string> => ({
+  MAP_BAN: String(DEFAULT_TIMER_SECONDS),
+  MAP_PICK: String(DEFAULT_TIMER_SECONDS),
+  DECIDER: String(DEFAULT_TIMER_SECONDS),
+  AGENT_BAN: String(DEFAULT_TIMER_SECONDS),
+  AGENT_PICK: String(DEFAULT_TIMER_SECONDS),
+});
+
+const inputsFromConfig = (config: RustTimerConfig): Record<ActionType, string> => {
+  const inputs = defaultInputs();
+  for (const [actionType] of PHASE_TIMER_LABELS) {
+    inputs[actionType] = String(config.phase_timers[actionType] ?? config.default_seconds);
+  }
+  return inputs;
+};
+
+export function PhaseTimerSettings() {
+  const setPhaseTimers = useTimerStore((state) => state.setPhaseTimers);
+  const getTimerConfig = useTimerStore((state) => state.getTimerConfig);
+
+  const [inputs, setInputs] = useState<Record<ActionType, string>>(defaultInputs);
+  const [status, setStatus] = useState<string | null>(null);
+
+  // Show what Rust actually has configured, e.g. after a window reload
+  useEffect(() => {
+    getTimerConfig()
+      .then((config) => setInputs(inputsFromConfig(config)))
+      .catch(() => setStatus('Could not load timer config; showing defaults.'));
+  }, [getTimerConfig]);
+
+  const handleApply = async () => {
+    const timers: PhaseTimers = {};
+    for (const [actionType, label] of PHASE_TIMER_LABELS) {
+      const seconds = Number(inputs[actionType]);
+      if (!Number.isInteger(seconds) || seconds <= 0) {
+        setStatus(`${label} timer must be a whole number of seconds greater than 0.`);
+        return;
+      }
+      timers[actionType] = seconds;
+    }
+
+    try {
+      await setPhaseTimers(timers);
+      setStatus('Phase timers applied from the next timer reset.');
+    } catch (error) {
+      setStatus(`Failed to apply phase timers: ${error}`);
+    }
+  };
+
+  return (
+    <div className="text-xs bg-tokyo-surface-light border border-tokyo-border-light rounded p-2 space-y-2">
+      <div className="text-tokyo-text-dim">Phase Timers (seconds):</div>
+      <div className="grid grid-cols-2 gap-x-2 gap-y-1">
+        {PHASE_TIMER_LABELS.map(([actionType, label]) => (
+          <label key={actionType} className="flex items-center justify-between gap-2">
+            <span className="text-tokyo-text">{label}</span>
+            <input
+              type="number"
+              min={1}
+              value={inputs[actionType]}
+              onChange={(e) => setInputs({ ...inputs, [actionType]: e.target.value })}
+              className="w-14 px-1 py-0.5 bg-tokyo-surface border border-tokyo-border-light rounded text-tokyo-text text-right focus:border-tokyo-blue focus:ring-1 focus:ring-tokyo-blue"
+            />
+          </label>
+        ))}
+      </div>
+      <button
+        onClick={handleApply}
+        className="w-full px-3 py-1.5 bg-tokyo-accent hover:bg-tokyo-blue text-white rounded text-sm font-medium transition-colors"
+      >
+        APPLY TIMERS
+      </button>
+      {status && <div className="text-tokyo-text-dim">{status}</div>}
+    </div>
+  );
+}
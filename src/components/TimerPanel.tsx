@@ -1,5 +1,6 @@
 import { useTournamentStore } from '../services/adminStore';
 import { getTurnInfo } from '../utils/tournamentHelpers';
+import { PhaseTimerSettings } from './PhaseTimerSettings';
 
 export function TimerPanel() {
   const {
@@ -7,6 +8,7 @@ export function TimerPanel() {
     timerSeconds,
     startTimer,
     pauseTimer,
+    finishTimer,
     resetTimer,
     actionNumber,
     firstPlayer,
@@ -84,6 +86,14 @@ export function TimerPanel() {
           {timerState === 'paused' ? 'RESUME' : 'PAUSE'}
         </button>
 
+        <button
+          onClick={finishTimer}
+          disabled={timerState !== 'running' && timerState !== 'paused'}
+          className="px-4 py-2 bg-tokyo-orange hover:bg-tokyo-red disabled:bg-tokyo-border disabled:cursor-not-allowed text-tokyo-background rounded font-medium transition-colors"
+        >
+          FINISH
+        </button>
+
         <button
           onClick={resetTimer}
           className="px-4 py-2 bg-tokyo-red hover:bg-tokyo-pink text-white rounded font-medium transition-colors"
@@ -92,6 +102,9 @@ export function TimerPanel() {
         </button>
       </div>
 
+      {/* Per-action turn durations */}
+      <PhaseTimerSettings />
+
       {/* Event Control (moved from Turn Control) */}
       <div className="mt-2">
         <button
@@ -1,5 +1,11 @@
 // Types
-export type { TimerStatus, TimerState } from './types';
+export type {
+  TimerStatus,
+  TimerState,
+  PhaseTimers,
+  RustTimerConfig,
+  RustTimerFinishedPayload,
+} from './types';
 
 // Engine (pure state machine)
 export { TimerEngine } from './TimerEngine';
@@ -1,5 +1,7 @@
 // Pure timer types - framework agnostic
 
+import type { ActionType, Player } from '../tournament/types';
+
 export type TimerStatus = 'ready' | 'running' | 'paused' | 'finished';
 
 export interface TimerState {
@@ -18,3 +20,28 @@ export interface RustTimerPayload {
   initial_seconds: number;
   timestamp_ms: number;
 }
+
+/**
+ * Payload received from Rust timer-finished events
+ * Matches TimerFinishedPayload in src-tauri/src/timer/service.rs
+ * player is set when the admin ends a turn early, null when time ran out
+ */
+export interface RustTimerFinishedPayload {
+  player: Player | null;
+}
+
+/**
+ * Per-action turn durations in seconds
+ * Action types without an entry use DEFAULT_TIMER_SECONDS
+ */
+export type PhaseTimers = Partial<Record<ActionType, number>>;
+
+/**
+ * Effective timer configuration returned by get_timer_config
+ * Matches TimerConfig in src-tauri/src/timer/state.rs
+ */
+export interface RustTimerConfig {
+  default_seconds: number;
+  initial_seconds: number;
+  phase_timers: PhaseTimers;
+}
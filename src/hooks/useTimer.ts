@@ -27,6 +27,7 @@ export function useTimerActions() {
   return {
     startTimer: useTimerStore((state) => state.startTimer),
     pauseTimer: useTimerStore((state) => state.pauseTimer),
+    finishTimer: useTimerStore((state) => state.finishTimer),
     resetTimer: useTimerStore((state) => state.resetTimer),
   };
 }
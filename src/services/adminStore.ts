@@ -21,7 +21,8 @@
 // ============================================
 
 import { create } from 'zustand';
-import type { TournamentStore, Player } from '../types/admin.types';
+import type { TournamentStore, Player, ActionType } from '../types/admin.types';
+import { getActionType, TOTAL_ACTIONS } from '../core/tournament';
 
 // Import from new stores
 import { useTournamentStore as useNewTournamentStore } from '../store/tournamentStore';
@@ -62,6 +63,22 @@ function scheduleAutoAdvanceOnceForCurrentAction() {
   }, 0);
 }
 
+// ============================================
+// Phase timer lookup
+// ============================================
+// Timer resets for a live turn pass the turn's action type so Rust can apply the
+// per-action phase timer. Outside a live turn the default duration is used.
+function currentTurnActionType(): ActionType | undefined {
+  const { eventStarted, currentPhase, actionNumber } = useNewTournamentStore.getState();
+  if (!eventStarted || currentPhase === 'CONCLUSION') return undefined;
+  if (actionNumber < 1 || actionNumber > TOTAL_ACTIONS) return undefined;
+  return getActionType(actionNumber);
+}
+
+function resetTimerForCurrentTurn() {
+  useTimerStore.getState().resetTimer(undefined, currentTurnActionType());
+}
+
 // ============================================
 // Facade store subscriptions (keep facade in sync)
 // ============================================
@@ -226,26 +243,26 @@ export const useTournamentStore = create<TournamentStore>((set, get) => {
 
     autoAdvanceTurn: () => {
       useNewTournamentStore.getState().autoAdvanceTurn();
-      useTimerStore.getState().resetTimer();
+      resetTimerForCurrentTurn();
       set(syncFromNewStores());
     },
 
     advancePhase: () => {
       useNewTournamentStore.getState().advancePhase();
-      useTimerStore.getState().resetTimer();
+      resetTimerForCurrentTurn();
       set(syncFromNewStores());
     },
 
     startEvent: () => {
       useNewTournamentStore.getState().startEvent();
-      useTimerStore.getState().resetTimer();
+      resetTimerForCurrentTurn();
       set(syncFromNewStores());
       forceEmit();
     },
 
     resetTurn: () => {
       useNewTournamentStore.getState().resetTurn();
-      useTimerStore.getState().resetTimer();
+      resetTimerForCurrentTurn();
       set(syncFromNewStores());
       forceEmit();
     },
@@ -388,8 +405,16 @@ export const useTournamentStore = create<TournamentStore>((set, get) => {
       forceEmit();
     },
 
+    finishTimer: () => {
+      // timer-finished from Rust drives the usual reveal and auto-advance
+      const player = useNewTournamentStore.getState().currentPlayer ?? undefined;
+      useTimerStore.getState().finishTimer(player);
+      set(syncFromNewStores());
+      forceEmit();
+    },
+
     resetTimer: () => {
-      useTimerStore.getState().resetTimer();
+      resetTimerForCurrentTurn();
       useNewTournamentStore.getState().setPendingSelection(null);
       set(syncFromNewStores());
       forceEmit();
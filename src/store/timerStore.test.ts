@@ -78,6 +78,26 @@ describe('timerStore', () => {
     });
   });
 
+  describe('finishTimer', () => {
+    it('should invoke finish_timer command with the current player', async () => {
+      vi.mocked(invoke).mockResolvedValueOnce({
+        status: 'finished',
+        seconds: 0,
+        initial_seconds: 3,
+      });
+
+      await useTimerStore.getState().finishTimer('P1');
+
+      expect(invoke).toHaveBeenCalledWith('finish_timer', { player: 'P1' });
+    });
+
+    it('should throw error if command fails', async () => {
+      vi.mocked(invoke).mockRejectedValueOnce(new Error('Cannot finish timer'));
+
+      await expect(useTimerStore.getState().finishTimer()).rejects.toThrow('Cannot finish timer');
+    });
+  });
+
   describe('resetTimer', () => {
     it('should invoke reset_timer command with default seconds', async () => {
       vi.mocked(invoke).mockResolvedValueOnce({
@@ -102,6 +122,57 @@ describe('timerStore', () => {
 
       expect(invoke).toHaveBeenCalledWith('reset_timer', { seconds: 30 });
     });
+
+    it('should let Rust resolve the phase timer when given an action type', async () => {
+      vi.mocked(invoke).mockResolvedValueOnce({
+        status: 'ready',
+        seconds: 20,
+        initial_seconds: 20,
+      });
+
+      await useTimerStore.getState().resetTimer(undefined, 'MAP_BAN');
+
+      expect(invoke).toHaveBeenCalledWith('reset_timer', {
+        seconds: undefined,
+        actionType: 'MAP_BAN',
+      });
+    });
+  });
+
+  describe('setPhaseTimers', () => {
+    it('should invoke set_phase_timers command with timers', async () => {
+      const timers = { MAP_BAN: 20, AGENT_PICK: 40 };
+      vi.mocked(invoke).mockResolvedValueOnce(timers);
+
+      const result = await useTimerStore.getState().setPhaseTimers(timers);
+
+      expect(invoke).toHaveBeenCalledWith('set_phase_timers', { timers });
+      expect(result).toEqual(timers);
+    });
+
+    it('should throw error if command fails', async () => {
+      vi.mocked(invoke).mockRejectedValueOnce(new Error('Unknown action type'));
+
+      await expect(useTimerStore.getState().setPhaseTimers({ DECIDER: 15 })).rejects.toThrow(
+        'Unknown action type'
+      );
+    });
+  });
+
+  describe('getTimerConfig', () => {
+    it('should invoke get_timer_config command', async () => {
+      const config = {
+        default_seconds: 30,
+        initial_seconds: 20,
+        phase_timers: { MAP_BAN: 20 },
+      };
+      vi.mocked(invoke).mockResolvedValueOnce(config);
+
+      const result = await useTimerStore.getState().getTimerConfig();
+
+      expect(invoke).toHaveBeenCalledWith('get_timer_config');
+      expect(result).toEqual(config);
+    });
   });
 
   describe('_updateFromRust', () => {
@@ -4,7 +4,15 @@ import { create } from 'zustand';
 import { subscribeWithSelector } from 'zustand/middleware';
 import { invoke } from '@tauri-apps/api/core';
 import { listen, UnlistenFn } from '@tauri-apps/api/event';
-import type { TimerState, TimerStatus, RustTimerPayload } from '../core/timer/types';
+import type {
+  TimerState,
+  TimerStatus,
+  RustTimerPayload,
+  RustTimerConfig,
+  RustTimerFinishedPayload,
+  PhaseTimers,
+} from '../core/timer/types';
+import type { ActionType, Player } from '../core/tournament/types';
 import { DEFAULT_TIMER_SECONDS } from '../core/tournament/constants';
 
 // ============================================
@@ -15,7 +23,10 @@ export interface TimerStoreState extends TimerState {
   // Actions
   startTimer: () => Promise<void>;
   pauseTimer: () => Promise<void>;
-  resetTimer: (seconds?: number) => Promise<void>;
+  finishTimer: (player?: Player) => Promise<void>;
+  resetTimer: (seconds?: number, actionType?: ActionType) => Promise<void>;
+  setPhaseTimers: (timers: PhaseTimers) => Promise<PhaseTimers>;
+  getTimerConfig: () => Promise<RustTimerConfig>;
 
   // Callback registration for timer events
   onTimerFinished: (() => void) | null;
@@ -64,15 +75,45 @@ export const useTimerStore = create<TimerStoreState>()(
       }
     },
 
-    resetTimer: async (seconds?: number) => {
+    finishTimer: async (player?: Player) => {
       try {
-        await invoke('reset_timer', { seconds: seconds ?? INITIAL_SECONDS });
+        // Rust forwards the player in the timer-finished payload
+        await invoke('finish_timer', { player });
+      } catch (error) {
+        console.error('Failed to finish timer:', error);
+        throw error;
+      }
+    },
+
+    resetTimer: async (seconds?: number, actionType?: ActionType) => {
+      try {
+        // With an action type, Rust resolves the duration from the phase timers
+        const args = actionType ? { seconds, actionType } : { seconds: seconds ?? INITIAL_SECONDS };
+        await invoke('reset_timer', args);
       } catch (error) {
         console.error('Failed to reset timer:', error);
         throw error;
       }
     },
 
+    setPhaseTimers: async (timers: PhaseTimers) => {
+      try {
+        return await invoke<PhaseTimers>('set_phase_timers', { timers });
+      } catch (error) {
+        console.error('Failed to set phase timers:', error);
+        throw error;
+      }
+    },
+
+    getTimerConfig: async () => {
+      try {
+        return await invoke<RustTimerConfig>('get_timer_config');
+      } catch (error) {
+        console.error('Failed to get timer config:', error);
+        throw error;
+      }
+    },
+
     // ----------------------------------------
     // Callback Registration
     // ----------------------------------------
@@ -131,7 +172,7 @@ export async function initializeTimerEventListeners(): Promise<void> {
       useTimerStore.getState()._updateFromRust(event.payload);
     });
 
-    finishedUnlisten = await listen('timer-finished', () => {
+    finishedUnlisten = await listen<RustTimerFinishedPayload>('timer-finished', () => {
       const callback = useTimerStore.getState().onTimerFinished;
       if (callback) {
         // Defer to allow state to settle
@@ -71,6 +71,7 @@ export interface TournamentActions {
   // Timer controls
   startTimer: () => void;
   pauseTimer: () => void;
+  finishTimer: () => void; // end the turn early, as if time ran out
   resetTimer: () => void;
 
   // Tournament management